@@ -23,7 +23,28 @@
 //! assert_eq!(csd_int, "+00-00");
 //! ```
 
+#[cfg(feature = "bigint")]
+use num_bigint::BigInt;
+use num_traits::{PrimInt, Signed};
 use std::f64;
+use std::fmt;
+use std::ops::{Add, Mul, Neg, Sub};
+use std::str::FromStr;
+
+/// Ceiling of the base-2 logarithm of a strictly positive integer.
+///
+/// Used by the generic integer conversions to size the initial power of two as
+/// `ceil_log2(3 * |value|) - 1`, i.e. `ceil(log2(|value| * 3 / 2))` taken
+/// exactly, without leaving the integer domain.
+fn ceil_log2<T: PrimInt>(n: T) -> u32 {
+    let bits = T::zero().count_zeros();
+    let floor = bits - 1 - n.leading_zeros();
+    if (n & (n - T::one())).is_zero() {
+        floor
+    } else {
+        floor + 1
+    }
+}
 
 /// Convert a decimal number to CSD representation with specified decimal places.
 ///
@@ -94,6 +115,50 @@ pub fn to_csd(decimal_value: f64, places: i32) -> String {
     csd_list.join("")
 }
 
+/// Convert an exact fixed-point value to CSD representation.
+///
+/// Unlike [`to_csd`], which scales by `1.5` and compares against `2.0.powi(..)`
+/// in `f64` and therefore misconverts coefficients that are not exactly
+/// representable, this path is bit-exact. The input is given as a scaled
+/// integer `mantissa` together with the number of fractional bits `places`, so
+/// the represented value is `mantissa / 2^places`. The integer CSD recurrence
+/// runs on `mantissa` directly and the radix point is then inserted `places`
+/// digits from the right, left-padding with `0` (and a leading `0.` when the
+/// integer part is empty).
+///
+/// # Arguments
+///
+/// * `mantissa` - The value scaled by `2^places`, i.e. `round(value * 2^places)`
+/// * `places` - Number of fractional bits
+///
+/// # Examples
+///
+/// ```
+/// use csdigit::to_csd_exact;
+/// // 28.5 == 57 / 2^1
+/// assert_eq!(to_csd_exact(57, 1), "+00-00.+");
+/// // -0.5 == -1 / 2^1
+/// assert_eq!(to_csd_exact(-1, 1), "0.-");
+/// assert_eq!(to_csd_exact(0, 2), "0.00");
+/// assert_eq!(to_csd_exact(0, 0), "0.");
+/// ```
+pub fn to_csd_exact(mantissa: i128, places: u32) -> String {
+    let digits = to_csd_generic(mantissa);
+    let places = places as usize;
+
+    if places == 0 {
+        return format!("{}.", digits);
+    }
+
+    let total = digits.chars().count();
+    if total <= places {
+        format!("0.{}{}", "0".repeat(places - total), digits)
+    } else {
+        let split = total - places;
+        format!("{}.{}", &digits[..split], &digits[split..])
+    }
+}
+
 /// Convert an integer to CSD representation.
 ///
 /// # Arguments
@@ -108,24 +173,60 @@ pub fn to_csd(decimal_value: f64, places: i32) -> String {
 /// assert_eq!(to_csd_i(0), "0");
 /// ```
 pub fn to_csd_i(decimal_value: i32) -> String {
-    if decimal_value == 0 {
+    // Widen to i64 so the internal `3 * value` never overflows across the full
+    // i32 input range (it does for |value| > 715_827_882 at i32 width).
+    to_csd_generic(decimal_value as i64)
+}
+
+/// Convert any signed integer to CSD representation.
+///
+/// This is the width-agnostic backing implementation for [`to_csd_i`]: it works
+/// for `i64`, `i128`, or any `num_traits::PrimInt + Signed` type, and replaces
+/// the old `3 * value` / `1 << rem` arithmetic with overflow-checked trait
+/// methods so coefficients beyond `2^30` convert without silently wrapping.
+///
+/// # Examples
+///
+/// ```
+/// use csdigit::to_csd_generic;
+/// assert_eq!(to_csd_generic(28_i64), "+00-00");
+/// assert_eq!(to_csd_generic(0_i128), "0");
+/// // Wide types convert without the old `3 * value` / `1 << rem` overflow.
+/// use csdigit::to_decimal;
+/// assert_eq!(to_decimal(&to_csd_generic(1_234_567_890_123_i64)), 1_234_567_890_123_f64);
+/// ```
+pub fn to_csd_generic<T>(decimal_value: T) -> String
+where
+    T: PrimInt + Signed,
+{
+    if decimal_value.is_zero() {
         return "0".to_string();
     }
 
+    let two = T::one() + T::one();
+    let three = two + T::one();
+
     let mut value = decimal_value;
-    let rem = ((value.abs() * 3 / 2) as f64).log2().ceil() as u32;
-    let mut p2n = 1 << rem;
+    // rem = ceil(log2(|value| * 3 / 2)), computed as ceil(log2(|value| * 3)) - 1 so
+    // values whose half-power lands between two powers of two (e.g. 1) still size
+    // their leading digit correctly.
+    let scaled = value
+        .abs()
+        .checked_mul(&three)
+        .expect("CSD conversion overflowed");
+    let rem = ceil_log2(scaled) - 1;
+    let mut p2n = T::one() << (rem as usize);
     let mut csd_list = Vec::new();
 
-    while p2n > 1 {
+    while p2n > T::one() {
         let p2n_half = p2n >> 1;
-        let det = 3 * value;
-        if det > p2n as i32 {
+        let det = three.checked_mul(&value).expect("CSD conversion overflowed");
+        if det > p2n {
             csd_list.push("+".to_string());
-            value -= p2n_half as i32;
-        } else if det < -(p2n as i32) {
+            value = value - p2n_half;
+        } else if det < T::zero() - p2n {
             csd_list.push("-".to_string());
-            value += p2n_half as i32;
+            value = value + p2n_half;
         } else {
             csd_list.push("0".to_string());
         }
@@ -135,7 +236,127 @@ pub fn to_csd_i(decimal_value: i32) -> String {
     csd_list.join("")
 }
 
-/// Convert a CSD string to a decimal number.
+/// Convert an arbitrary-precision integer to CSD representation.
+///
+/// Available with the `bigint` feature. This runs the same greedy signed-digit
+/// recurrence as [`to_csd_generic`] but over [`num_bigint::BigInt`], so
+/// hundred-bit filter coefficients convert exactly with no overflow or
+/// floating-point precision loss.
+///
+/// # Examples
+///
+/// ```
+/// # #[cfg(feature = "bigint")] {
+/// use csdigit::to_csd_big;
+/// use num_bigint::BigInt;
+/// assert_eq!(to_csd_big(&BigInt::from(28)), "+00-00");
+/// assert_eq!(to_csd_big(&BigInt::from(0)), "0");
+/// # }
+/// ```
+#[cfg(feature = "bigint")]
+pub fn to_csd_big(value: &BigInt) -> String {
+    use num_traits::{One, Zero};
+
+    if value.is_zero() {
+        return "0".to_string();
+    }
+
+    let three = BigInt::from(3);
+    let scaled = value.abs() * &three;
+    // rem = ceil(log2(|value| * 3)) - 1, matching to_csd_generic.
+    let floor = scaled.bits() - 1;
+    let ceil_log2 = if scaled == (BigInt::one() << floor as usize) {
+        floor
+    } else {
+        floor + 1
+    };
+    let rem = ceil_log2 - 1;
+
+    let mut p2n = BigInt::one() << rem as usize;
+    let mut val = value.clone();
+    let mut csd = String::new();
+
+    while p2n > BigInt::one() {
+        let p2n_half = &p2n >> 1;
+        let det = &three * &val;
+        if det > p2n {
+            csd.push('+');
+            val -= &p2n_half;
+        } else if det < -(&p2n) {
+            csd.push('-');
+            val += &p2n_half;
+        } else {
+            csd.push('0');
+        }
+        p2n = p2n_half;
+    }
+
+    csd
+}
+
+/// Convert a CSD string to an arbitrary-precision integer.
+///
+/// Available with the `bigint` feature. The companion to [`to_csd_big`] for
+/// integer (radix-point-free) CSD strings.
+///
+/// # Examples
+///
+/// ```
+/// # #[cfg(feature = "bigint")] {
+/// use csdigit::big_to_decimal;
+/// use num_bigint::BigInt;
+/// assert_eq!(big_to_decimal("+00-00"), BigInt::from(28));
+/// # }
+/// ```
+#[cfg(feature = "bigint")]
+pub fn big_to_decimal(csd: &str) -> BigInt {
+    use num_traits::Zero;
+
+    let mut acc = BigInt::zero();
+    for ch in csd.chars() {
+        match ch {
+            '+' => acc = acc * 2 + 1,
+            '-' => acc = acc * 2 - 1,
+            '0' => acc *= 2,
+            _ => {}
+        }
+    }
+    acc
+}
+
+/// Error produced when a CSD string cannot be parsed.
+///
+/// Returned by [`try_to_decimal`] (and [`Csd::from_str`]) so callers can
+/// distinguish a genuine zero from malformed input, in the spirit of
+/// `rust_decimal::Error`.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum CsdError {
+    /// The input string was empty.
+    EmptyString,
+    /// The input contained more than one radix point.
+    MultipleRadixPoints,
+    /// A character other than `+`, `-`, `0` or `.` was found at the given index.
+    InvalidCharacter { index: usize, ch: char },
+}
+
+impl fmt::Display for CsdError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            CsdError::EmptyString => write!(f, "empty CSD string"),
+            CsdError::MultipleRadixPoints => write!(f, "more than one radix point in CSD string"),
+            CsdError::InvalidCharacter { index, ch } => {
+                write!(f, "invalid character {:?} at index {}", ch, index)
+            }
+        }
+    }
+}
+
+impl std::error::Error for CsdError {}
+
+/// Convert a CSD string to a decimal number, rejecting malformed input.
+///
+/// Unlike [`to_decimal`], which silently returns `0.0` for malformed input,
+/// this reports the precise problem via [`CsdError`].
 ///
 /// # Arguments
 ///
@@ -144,35 +365,44 @@ pub fn to_csd_i(decimal_value: i32) -> String {
 /// # Examples
 ///
 /// ```
-/// use csdigit::to_decimal;
-/// assert_eq!(to_decimal("+00-00.+"), 28.5);
-/// assert_eq!(to_decimal("0.-"), -0.5);
-/// assert_eq!(to_decimal("0"), 0.0);
-/// assert_eq!(to_decimal("0.0"), 0.0);
-/// assert_eq!(to_decimal("0.+"), 0.5);
+/// use csdigit::{try_to_decimal, CsdError};
+/// assert_eq!(try_to_decimal("+00-00.+").unwrap(), 28.5);
+/// assert_eq!(try_to_decimal(""), Err(CsdError::EmptyString));
+/// assert_eq!(try_to_decimal("0.0.0"), Err(CsdError::MultipleRadixPoints));
+/// assert_eq!(
+///     try_to_decimal("+0x"),
+///     Err(CsdError::InvalidCharacter { index: 2, ch: 'x' })
+/// );
 /// ```
-pub fn to_decimal(csd: &str) -> f64 {
+pub fn try_to_decimal(csd: &str) -> Result<f64, CsdError> {
+    if csd.is_empty() {
+        return Err(CsdError::EmptyString);
+    }
+    if csd.matches('.').count() > 1 {
+        return Err(CsdError::MultipleRadixPoints);
+    }
+    for (index, ch) in csd.chars().enumerate() {
+        if !matches!(ch, '+' | '-' | '0' | '.') {
+            return Err(CsdError::InvalidCharacter { index, ch });
+        }
+    }
+
     if !csd.contains('.') {
-        let mut integral = 0;
+        let mut integral = 0i64;
         for ch in csd.chars() {
             integral *= 2;
             match ch {
                 '+' => integral += 1,
                 '-' => integral -= 1,
-                '0' => {}
-                _ => log::info!("Encounter unknown character {}", ch),
+                _ => {}
             }
         }
-        return integral as f64;
-    }
-
-    let parts: Vec<&str> = csd.split('.').collect();
-    if parts.len() != 2 {
-        return 0.0;
+        return Ok(integral as f64);
     }
 
-    let integral_part = parts[0];
-    let fractional_part = parts[1];
+    let mut parts = csd.splitn(2, '.');
+    let integral_part = parts.next().unwrap_or("");
+    let fractional_part = parts.next().unwrap_or("");
 
     let mut integral_float = 0.0;
     for ch in integral_part.chars() {
@@ -180,8 +410,7 @@ pub fn to_decimal(csd: &str) -> f64 {
         match ch {
             '+' => integral_float += 1.0,
             '-' => integral_float -= 1.0,
-            '0' => {}
-            _ => log::info!("Encounter unknown character {}", ch),
+            _ => {}
         }
     }
 
@@ -191,13 +420,36 @@ pub fn to_decimal(csd: &str) -> f64 {
         match ch {
             '+' => fractional += scale,
             '-' => fractional -= scale,
-            '0' => {}
-            _ => log::info!("Encounter unknown character {}", ch),
+            _ => {}
         }
         scale /= 2.0;
     }
 
-    integral_float + fractional
+    Ok(integral_float + fractional)
+}
+
+/// Convert a CSD string to a decimal number.
+///
+/// This is the lossy wrapper over [`try_to_decimal`]: malformed input yields
+/// `0.0` rather than an error. Callers that need to reject bad input should use
+/// [`try_to_decimal`] instead.
+///
+/// # Arguments
+///
+/// * `csd` - The CSD string to convert
+///
+/// # Examples
+///
+/// ```
+/// use csdigit::to_decimal;
+/// assert_eq!(to_decimal("+00-00.+"), 28.5);
+/// assert_eq!(to_decimal("0.-"), -0.5);
+/// assert_eq!(to_decimal("0"), 0.0);
+/// assert_eq!(to_decimal("0.0"), 0.0);
+/// assert_eq!(to_decimal("0.+"), 0.5);
+/// ```
+pub fn to_decimal(csd: &str) -> f64 {
+    try_to_decimal(csd).unwrap_or(0.0)
 }
 
 /// Convert a decimal number to CSD representation with a maximum number of non-zero digits.
@@ -273,26 +525,58 @@ pub fn to_csdnnz(decimal_value: f64, nnz: i32) -> String {
 /// assert_eq!(to_csdnnz_i(158, 2), "+0+00000");
 /// ```
 pub fn to_csdnnz_i(decimal_value: i32, nnz: i32) -> String {
-    if decimal_value == 0 {
+    // Widen to i64 so the internal `3 * value` never overflows across the full
+    // i32 input range (it does for |value| > 715_827_882 at i32 width).
+    to_csdnnz_generic(decimal_value as i64, nnz)
+}
+
+/// Convert any signed integer to CSD representation with a maximum number of
+/// non-zero digits.
+///
+/// The width-agnostic backing implementation for [`to_csdnnz_i`], sharing the
+/// same overflow-checked recurrence as [`to_csd_generic`].
+///
+/// # Examples
+///
+/// ```
+/// use csdigit::to_csdnnz_generic;
+/// assert_eq!(to_csdnnz_generic(28_i64, 4), "+00-00");
+/// assert_eq!(to_csdnnz_generic(37_i128, 2), "+00+00");
+/// ```
+pub fn to_csdnnz_generic<T>(decimal_value: T, nnz: i32) -> String
+where
+    T: PrimInt + Signed,
+{
+    if decimal_value.is_zero() {
         return "0".to_string();
     }
 
+    let two = T::one() + T::one();
+    let three = two + T::one();
+
     let mut value = decimal_value;
-    let rem = ((value.abs() * 3 / 2) as f64).log2().ceil() as i32;
-    let mut p2n = 2_i32.pow(rem as u32);
+    // rem = ceil(log2(|value| * 3 / 2)), computed as ceil(log2(|value| * 3)) - 1 so
+    // values whose half-power lands between two powers of two (e.g. 1) still size
+    // their leading digit correctly.
+    let scaled = value
+        .abs()
+        .checked_mul(&three)
+        .expect("CSD conversion overflowed");
+    let rem = ceil_log2(scaled) - 1;
+    let mut p2n = T::one() << (rem as usize);
     let mut csd_list = Vec::new();
     let mut nnz_remaining = nnz;
 
-    while p2n > 1 {
+    while p2n > T::one() {
         let p2n_half = p2n >> 1;
-        let det = 3 * value;
+        let det = three.checked_mul(&value).expect("CSD conversion overflowed");
         if nnz_remaining > 0 && det > p2n {
             csd_list.push("+".to_string());
-            value -= p2n_half;
+            value = value - p2n_half;
             nnz_remaining -= 1;
-        } else if nnz_remaining > 0 && det < -p2n {
+        } else if nnz_remaining > 0 && det < T::zero() - p2n {
             csd_list.push("-".to_string());
-            value += p2n_half;
+            value = value + p2n_half;
             nnz_remaining -= 1;
         } else {
             csd_list.push("0".to_string());
@@ -303,6 +587,177 @@ pub fn to_csdnnz_i(decimal_value: i32, nnz: i32) -> String {
     csd_list.join("")
 }
 
+/// Integer value represented by a bare signed-digit string (no radix point).
+///
+/// Each digit contributes its weight relative to the least-significant digit,
+/// so `digits_value("+00-00")` is `28`. Shared by [`Csd`]'s arithmetic, which
+/// works in the exact scaled-integer domain before re-canonicalizing.
+fn digits_value(digits: &str) -> i128 {
+    let mut acc: i128 = 0;
+    for ch in digits.chars() {
+        acc *= 2;
+        match ch {
+            '+' => acc += 1,
+            '-' => acc -= 1,
+            _ => {}
+        }
+    }
+    acc
+}
+
+/// A canonical signed-digit value with a fixed radix-point position.
+///
+/// `Csd` pairs the canonical digit string with the number of fractional
+/// digits, and implements [`Add`], [`Sub`], [`Neg`] and [`Mul`] the way
+/// `rust_decimal::Decimal` exposes its operators. Sums, differences and
+/// products are re-canonicalized through the same conversion recurrence as the
+/// free functions (via [`to_csd_exact`]); negation flips every `+`/`-` digit,
+/// which keeps the form canonical. So a `Csd` is always in canonical form and
+/// round-trips with [`to_decimal`] / [`FromStr`] / [`Display`].
+///
+/// # Examples
+///
+/// ```
+/// use csdigit::Csd;
+/// let a: Csd = "+00-00.+".parse().unwrap(); // 28.5
+/// let b: Csd = "0.+".parse().unwrap();       // 0.5
+/// assert_eq!((a.clone() + b.clone()).to_decimal(), 29.0);
+/// assert_eq!((-b).to_decimal(), -0.5);
+/// ```
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Csd {
+    /// Canonical signed-digit string, most-significant digit first, no radix point.
+    digits: String,
+    /// Number of fractional digits (position of the radix point from the right).
+    point: usize,
+}
+
+impl Csd {
+    /// Build a `Csd` from a value scaled by `2^places`, i.e. `value * 2^places`.
+    ///
+    /// The mantissa is converted with [`to_csd_exact`], so the result is
+    /// canonical by construction.
+    pub fn from_mantissa(mantissa: i128, places: u32) -> Self {
+        Self::split_exact(&to_csd_exact(mantissa, places))
+    }
+
+    /// Split a `"int.frac"` CSD string produced by [`to_csd_exact`] into the
+    /// stored digit string and radix-point position.
+    fn split_exact(s: &str) -> Self {
+        let mut parts = s.splitn(2, '.');
+        let int_part = parts.next().unwrap_or("0");
+        let frac_part = parts.next().unwrap_or("");
+        let point = frac_part.chars().count();
+        Csd {
+            digits: format!("{}{}", int_part, frac_part),
+            point,
+        }
+    }
+
+    /// The integer value of the digit string, i.e. `value * 2^places`.
+    pub fn mantissa(&self) -> i128 {
+        digits_value(&self.digits)
+    }
+
+    /// Number of fractional digits.
+    pub fn places(&self) -> u32 {
+        self.point as u32
+    }
+
+    /// Convert back to a floating-point decimal.
+    pub fn to_decimal(&self) -> f64 {
+        self.mantissa() as f64 / 2.0_f64.powi(self.point as i32)
+    }
+}
+
+impl Neg for Csd {
+    type Output = Csd;
+
+    fn neg(self) -> Csd {
+        let digits = self
+            .digits
+            .chars()
+            .map(|c| match c {
+                '+' => '-',
+                '-' => '+',
+                other => other,
+            })
+            .collect();
+        Csd {
+            digits,
+            point: self.point,
+        }
+    }
+}
+
+impl Add for Csd {
+    type Output = Csd;
+
+    fn add(self, rhs: Csd) -> Csd {
+        // Align both operands at the radix point, add in the exact integer
+        // domain, then re-canonicalize through the conversion recurrence.
+        let places = self.point.max(rhs.point);
+        let lhs = self.mantissa() << (places - self.point);
+        let rhs = rhs.mantissa() << (places - rhs.point);
+        Csd::from_mantissa(lhs + rhs, places as u32)
+    }
+}
+
+impl Sub for Csd {
+    type Output = Csd;
+
+    fn sub(self, rhs: Csd) -> Csd {
+        self + (-rhs)
+    }
+}
+
+impl Mul for Csd {
+    type Output = Csd;
+
+    fn mul(self, rhs: Csd) -> Csd {
+        // The product of the shifted copies is the product of the mantissas,
+        // with the radix-point positions summed.
+        let places = self.point + rhs.point;
+        Csd::from_mantissa(self.mantissa() * rhs.mantissa(), places as u32)
+    }
+}
+
+impl fmt::Display for Csd {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        if self.point == 0 {
+            // No fractional digits: emit the bare integer form so FromStr round-trips.
+            write!(f, "{}", to_csd_generic(self.mantissa()))
+        } else {
+            write!(f, "{}", to_csd_exact(self.mantissa(), self.point as u32))
+        }
+    }
+}
+
+impl FromStr for Csd {
+    type Err = CsdError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        if s.is_empty() {
+            return Err(CsdError::EmptyString);
+        }
+        if s.matches('.').count() > 1 {
+            return Err(CsdError::MultipleRadixPoints);
+        }
+        for (index, ch) in s.chars().enumerate() {
+            if !matches!(ch, '+' | '-' | '0' | '.') {
+                return Err(CsdError::InvalidCharacter { index, ch });
+            }
+        }
+        let mut parts = s.splitn(2, '.');
+        let int_part = parts.next().unwrap_or("");
+        let frac_part = parts.next().unwrap_or("");
+        let point = frac_part.chars().count();
+        let mantissa = digits_value(&format!("{}{}", int_part, frac_part));
+        // Normalize to canonical form.
+        Ok(Csd::from_mantissa(mantissa, point as u32))
+    }
+}
+
 /// Find the longest repeated non-overlapping substring in a string.
 ///
 /// # Arguments
@@ -420,6 +875,209 @@ pub fn generate_csd_multiplier(csd: &str, n: usize, m: usize) -> String {
     verilog_code
 }
 
+/// Code-generation options for [`generate_csd_multiplier_ex`].
+///
+/// The default (`adder_tree = false`, `pipeline_stages = 0`) reproduces the
+/// flat combinational expression emitted by [`generate_csd_multiplier`].
+#[derive(Debug, Clone, Default)]
+pub struct CsdMultiplierOptions {
+    /// Emit a balanced adder tree (depth `ceil(log2(nonzero))`) instead of a
+    /// flat ripple of `+`/`-` terms. When `pipeline_stages > 0` the tree form
+    /// is emitted whatever this flag says.
+    pub adder_tree: bool,
+    /// Number of pipeline register stages to insert between tree levels. `0`
+    /// keeps the datapath purely combinational; a positive value adds `clk`/`rst`
+    /// ports and registers the first `pipeline_stages` tree levels.
+    pub pipeline_stages: usize,
+}
+
+/// A generated CSD multiplier module together with its timing metadata.
+///
+/// Returned by [`generate_csd_multiplier_ex`] so callers can feed port widths
+/// and latency into a larger generator.
+#[derive(Debug, Clone)]
+pub struct CsdMultiplier {
+    /// The generated Verilog source.
+    pub code: String,
+    /// Width of the `x` input port, in bits.
+    pub input_width: usize,
+    /// Width of the `result` output port, in bits.
+    pub output_width: usize,
+    /// Number of non-zero CSD digits (shifted terms summed).
+    pub nonzero_terms: usize,
+    /// Latency from input to output, in clock cycles (`0` when combinational).
+    pub latency: usize,
+}
+
+/// Generate a CSD multiplier module with adder-tree and pipelining options.
+///
+/// With [`CsdMultiplierOptions::default`] this wraps the flat expression of
+/// [`generate_csd_multiplier`]. Enabling `adder_tree` pairs the shifted terms
+/// recursively so the combinational depth is `ceil(log2(nonzero))` rather than
+/// linear; `pipeline_stages` then registers the first N tree levels, adding
+/// `clk`/`rst` ports and reporting the resulting latency.
+///
+/// # Examples
+///
+/// ```
+/// use csdigit::{generate_csd_multiplier_ex, CsdMultiplierOptions};
+/// let opts = CsdMultiplierOptions { adder_tree: true, pipeline_stages: 1 };
+/// let m = generate_csd_multiplier_ex("+00-00+0", 8, 7, &opts);
+/// assert!(m.code.contains("module csd_multiplier"));
+/// assert!(m.code.contains("input clk"));
+/// assert_eq!(m.nonzero_terms, 3);
+/// assert_eq!(m.latency, 1);
+/// ```
+pub fn generate_csd_multiplier_ex(
+    csd: &str,
+    n: usize,
+    m: usize,
+    options: &CsdMultiplierOptions,
+) -> CsdMultiplier {
+    if csd.len() != m + 1 {
+        panic!("CSD length {} doesn't match m={} (should be m+1)", csd.len(), m);
+    }
+
+    if !csd.chars().all(|c| c == '+' || c == '-' || c == '0') {
+        panic!("CSD string can only contain '+', '-', or '0'");
+    }
+
+    let terms: Vec<(usize, i32)> = csd
+        .chars()
+        .enumerate()
+        .filter_map(|(i, c)| match c {
+            '+' => Some((m - i, 1)),
+            '-' => Some((m - i, -1)),
+            _ => None,
+        })
+        .collect();
+    let nonzero_terms = terms.len();
+
+    // Fast path: the flat combinational form is exactly what the original
+    // generator emits, so reuse it verbatim.
+    if !options.adder_tree && options.pipeline_stages == 0 {
+        return CsdMultiplier {
+            code: generate_csd_multiplier(csd, n, m),
+            input_width: n,
+            output_width: n + m,
+            nonzero_terms,
+            latency: 0,
+        };
+    }
+
+    let w = n + m - 1;
+    let pipelined = options.pipeline_stages > 0;
+
+    let mut code = String::from("\nmodule csd_multiplier (");
+    code += &format!("\n    input signed [{}:0] x,      // Input value", n - 1);
+    if pipelined {
+        code += "\n    input clk,";
+        code += "\n    input rst,";
+    }
+    code += &format!(
+        "\n    output signed [{}:0] result // Result of multiplication",
+        w
+    );
+    code += "\n);";
+
+    if !terms.is_empty() {
+        code += "\n\n    // Create shifted versions of input";
+        let mut powers: Vec<usize> = terms.iter().map(|&(p, _)| p).collect();
+        powers.sort_unstable_by(|a, b| b.cmp(a));
+        powers.dedup();
+        for p in powers {
+            code += &format!("\n    wire signed [{}:0] x_shift{} = x <<< {};", w, p, p);
+        }
+    }
+
+    code += "\n\n    // CSD implementation (balanced adder tree)";
+
+    if terms.is_empty() {
+        code += "\n    assign result = 0;";
+        code += "\nendmodule\n";
+        return CsdMultiplier {
+            code,
+            input_width: n,
+            output_width: n + m,
+            nonzero_terms,
+            latency: 0,
+        };
+    }
+
+    let mut operands: Vec<String> = terms
+        .iter()
+        .map(|&(p, s)| {
+            if s > 0 {
+                format!("x_shift{}", p)
+            } else {
+                format!("-x_shift{}", p)
+            }
+        })
+        .collect();
+
+    let mut decls = String::new();
+    let mut reg_updates = String::new();
+    let mut reg_names: Vec<String> = Vec::new();
+    let mut latency = 0;
+    let mut level = 0;
+
+    while operands.len() > 1 {
+        let registered = pipelined && level < options.pipeline_stages;
+        let mut next = Vec::new();
+        for (idx, chunk) in operands.chunks(2).enumerate() {
+            let expr = if chunk.len() == 2 {
+                format!("{} + {}", chunk[0], chunk[1])
+            } else {
+                chunk[0].clone()
+            };
+            // A lone odd operand at a combinational level just carries forward.
+            if chunk.len() == 1 && !registered {
+                next.push(expr);
+                continue;
+            }
+            let name = format!("t{}_{}", level, idx);
+            if registered {
+                decls += &format!("\n    reg signed [{}:0] {};", w, name);
+                reg_updates += &format!("\n            {} <= {};", name, expr);
+                reg_names.push(name.clone());
+            } else {
+                decls += &format!("\n    wire signed [{}:0] {} = {};", w, name, expr);
+            }
+            next.push(name);
+        }
+        if registered {
+            latency += 1;
+        }
+        operands = next;
+        level += 1;
+    }
+
+    code += &decls;
+
+    if !reg_names.is_empty() {
+        code += "\n\n    always @(posedge clk or posedge rst) begin";
+        code += "\n        if (rst) begin";
+        for name in &reg_names {
+            code += &format!("\n            {} <= 0;", name);
+        }
+        code += "\n        end else begin";
+        code += &reg_updates;
+        code += "\n        end";
+        code += "\n    end";
+    }
+
+    code += &format!("\n\n    assign result = {};", operands[0]);
+    code += "\nendmodule\n";
+
+    CsdMultiplier {
+        code,
+        input_width: n,
+        output_width: n + m,
+        nonzero_terms,
+        latency,
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -438,6 +1096,47 @@ mod tests {
         assert_eq!(to_csd_i(0), "0");
     }
     
+    #[test]
+    fn test_csd_type() {
+        let a: Csd = "+00-00.+".parse().unwrap();
+        let b: Csd = "0.+".parse().unwrap();
+        assert_eq!(a.to_decimal(), 28.5);
+        assert_eq!(b.to_decimal(), 0.5);
+        assert_eq!((a.clone() + b.clone()).to_decimal(), 29.0);
+        assert_eq!((a.clone() - b.clone()).to_decimal(), 28.0);
+        assert_eq!((-b.clone()).to_decimal(), -0.5);
+        assert_eq!((a.clone() * b.clone()).to_decimal(), 14.25);
+        assert_eq!(Csd::from_mantissa(57, 1).to_string(), "+00-00.+");
+        // Integer (point == 0) values round-trip through FromStr -> Display.
+        let c: Csd = "+0+".parse().unwrap();
+        assert_eq!(c.to_string(), "+0+");
+    }
+
+    #[test]
+    fn test_to_csd_exact() {
+        assert_eq!(to_csd_exact(57, 1), "+00-00.+");
+        assert_eq!(to_csd_exact(-1, 1), "0.-");
+        assert_eq!(to_csd_exact(0, 2), "0.00");
+        assert_eq!(to_csd_exact(0, 0), "0.");
+        // Round-trips exactly: 57 / 2^1 == 28.5.
+        assert_eq!(to_decimal(&to_csd_exact(57, 1)), 28.5);
+    }
+
+    #[test]
+    fn test_to_csd_generic() {
+        assert_eq!(to_csd_generic(28_i64), "+00-00");
+        assert_eq!(to_csd_generic(0_i128), "0");
+        // Beyond the i32 range the old `3 * value` path would have overflowed.
+        assert_eq!(to_csd_generic(4_294_967_296_i64), format!("+{}", "0".repeat(32)));
+        assert_eq!(to_decimal(&to_csd_generic(1_234_567_890_123_i64)), 1_234_567_890_123_f64);
+    }
+
+    #[test]
+    fn test_to_csdnnz_generic() {
+        assert_eq!(to_csdnnz_generic(28_i64, 4), "+00-00");
+        assert_eq!(to_csdnnz_generic(37_i128, 2), "+00+00");
+    }
+
     #[test]
     fn test_to_decimal() {
         assert_eq!(to_decimal("+00-00.+"), 28.5);
@@ -447,6 +1146,19 @@ mod tests {
         assert_eq!(to_decimal("0.+"), 0.5);
     }
     
+    #[test]
+    fn test_try_to_decimal() {
+        assert_eq!(try_to_decimal("+00-00.+").unwrap(), 28.5);
+        assert_eq!(try_to_decimal(""), Err(CsdError::EmptyString));
+        assert_eq!(try_to_decimal("0.0.0"), Err(CsdError::MultipleRadixPoints));
+        assert_eq!(
+            try_to_decimal("+0x"),
+            Err(CsdError::InvalidCharacter { index: 2, ch: 'x' })
+        );
+        // Lossy wrapper still returns 0.0 for malformed input.
+        assert_eq!(to_decimal("0.0.0"), 0.0);
+    }
+
     #[test]
     fn test_to_csdnnz() {
         assert_eq!(to_csdnnz(28.5, 4), "+00-00.+");
@@ -463,6 +1175,17 @@ mod tests {
         assert_eq!(to_csdnnz_i(158, 2), "+0+00000");
     }
     
+    #[cfg(feature = "bigint")]
+    #[test]
+    fn test_to_csd_big() {
+        use num_bigint::BigInt;
+        assert_eq!(to_csd_big(&BigInt::from(28)), "+00-00");
+        assert_eq!(to_csd_big(&BigInt::from(0)), "0");
+        // 2^100 round-trips exactly, well beyond the i64 range.
+        let big = BigInt::from(2).pow(100);
+        assert_eq!(big_to_decimal(&to_csd_big(&big)), big);
+    }
+
     #[test]
     fn test_longest_repeated_substring() {
         assert_eq!(longest_repeated_substring("+-00+-00+-00+-0"), "+-00+-0");
@@ -475,4 +1198,24 @@ mod tests {
         assert!(verilog.contains("input signed [7:0] x"));
         assert!(verilog.contains("output signed [14:0] result"));
     }
+
+    #[test]
+    fn test_generate_csd_multiplier_ex() {
+        // Default options reproduce the flat combinational generator.
+        let flat = generate_csd_multiplier_ex("+00-00+0", 8, 7, &CsdMultiplierOptions::default());
+        assert_eq!(flat.code, generate_csd_multiplier("+00-00+0", 8, 7));
+        assert_eq!(flat.nonzero_terms, 3);
+        assert_eq!(flat.latency, 0);
+
+        // Adder tree with one pipeline stage adds clock/reset ports and latency.
+        let opts = CsdMultiplierOptions {
+            adder_tree: true,
+            pipeline_stages: 1,
+        };
+        let piped = generate_csd_multiplier_ex("+00-00+0", 8, 7, &opts);
+        assert!(piped.code.contains("input clk"));
+        assert!(piped.code.contains("always @(posedge clk or posedge rst)"));
+        assert_eq!(piped.output_width, 15);
+        assert_eq!(piped.latency, 1);
+    }
 }
\ No newline at end of file